@@ -0,0 +1,342 @@
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+use serde::{Deserialize, Serialize};
+use sqlx::sqlite::{SqliteConnectOptions, SqlitePoolOptions};
+use sqlx::{Row, SqlitePool};
+use tauri::{AppHandle, State};
+use walkdir::WalkDir;
+
+use crate::exif_reader::extract_photo_fields;
+use crate::settings::SettingsState;
+
+/// Managed state wrapping the single pool opened in `.setup()`, mirroring
+/// how `SettingsState` is managed in `settings.rs`/`main.rs` — commands
+/// borrow the pool instead of opening a fresh connection per call.
+pub struct DbState(pub SqlitePool);
+
+const SUPPORTED_EXTENSIONS: &[&str] = &["jpg", "jpeg", "png", "tif", "tiff", "webp"];
+
+/// Counts of rows touched by a [`scan_dir`] call.
+#[derive(Debug, Default, Serialize)]
+pub struct ScanSummary {
+    pub added: u32,
+    pub updated: u32,
+    pub removed: u32,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct PhotoFilter {
+    pub camera: Option<String>,
+    pub date_from: Option<String>,
+    pub date_to: Option<String>,
+}
+
+#[derive(Debug, Serialize, sqlx::FromRow)]
+pub struct Photo {
+    pub path: String,
+    pub capture_date: Option<String>,
+    pub camera: Option<String>,
+    pub gps_latitude: Option<f64>,
+    pub gps_longitude: Option<f64>,
+    pub width: Option<i64>,
+    pub height: Option<i64>,
+    pub size: i64,
+    pub modified: i64,
+}
+
+/// Opens the single pool for the app's lifetime. Called once from
+/// `.setup()`; the result is handed to `app.manage()` as [`DbState`].
+pub async fn init_pool(app: &AppHandle) -> Result<SqlitePool, String> {
+    let data_dir = app
+        .path_resolver()
+        .app_data_dir()
+        .ok_or_else(|| "could not resolve app data directory".to_string())?;
+    std::fs::create_dir_all(&data_dir).map_err(|e| e.to_string())?;
+    let db_path = data_dir.join("library.sqlite");
+
+    // `SqliteConnectOptions::filename` takes a real path rather than a URL
+    // string, so it doesn't choke on Windows paths (`C:\...`) the way
+    // hand-building a `sqlite://` URL would.
+    let options = SqliteConnectOptions::new()
+        .filename(&db_path)
+        .create_if_missing(true);
+
+    let pool = SqlitePoolOptions::new()
+        .max_connections(4)
+        .connect_with(options)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    ensure_schema(&pool).await?;
+
+    Ok(pool)
+}
+
+async fn ensure_schema(pool: &SqlitePool) -> Result<(), String> {
+    sqlx::query(
+        "CREATE TABLE IF NOT EXISTS photos (
+            path TEXT PRIMARY KEY,
+            capture_date TEXT,
+            camera TEXT,
+            gps_latitude REAL,
+            gps_longitude REAL,
+            width INTEGER,
+            height INTEGER,
+            size INTEGER NOT NULL,
+            modified INTEGER NOT NULL
+        )",
+    )
+    .execute(pool)
+    .await
+    .map_err(|e| e.to_string())?;
+
+    Ok(())
+}
+
+/// Walks `path`, upserts a row per photo keyed by absolute path, and drops
+/// rows for files that are no longer present. Files whose size and mtime
+/// are unchanged since the last scan are skipped rather than re-read, so
+/// incremental rescans of a large library stay cheap. `path` defaults to
+/// the configured library root.
+#[tauri::command(async)]
+pub async fn scan_dir(
+    path: Option<String>,
+    settings: State<'_, SettingsState>,
+    db: State<'_, DbState>,
+) -> Result<ScanSummary, String> {
+    let path = settings.resolve(path);
+    scan_dir_impl(path, &db.0).await
+}
+
+async fn scan_dir_impl(path: PathBuf, pool: &SqlitePool) -> Result<ScanSummary, String> {
+    // Canonicalize once so every row written this scan, and the prefix used
+    // to scope the removal pass below, agree on the same root.
+    let scan_root = path.canonicalize().unwrap_or(path);
+    let mut summary = ScanSummary::default();
+    let mut seen_paths: HashSet<String> = HashSet::new();
+
+    for entry in WalkDir::new(&scan_root).into_iter().filter_map(|e| e.ok()) {
+        if !entry.file_type().is_file() || !is_supported_image(entry.path()) {
+            continue;
+        }
+
+        let absolute = entry.path().to_string_lossy().into_owned();
+        let metadata = entry.metadata().map_err(|e| e.to_string())?;
+        let size = metadata.len() as i64;
+        let modified = metadata
+            .modified()
+            .ok()
+            .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+            .map(|d| d.as_secs() as i64)
+            .unwrap_or(0);
+
+        seen_paths.insert(absolute.clone());
+
+        let existing = sqlx::query("SELECT size, modified FROM photos WHERE path = ?")
+            .bind(&absolute)
+            .fetch_optional(pool)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        if let Some(row) = &existing {
+            let existing_size: i64 = row.try_get("size").map_err(|e| e.to_string())?;
+            let existing_modified: i64 = row.try_get("modified").map_err(|e| e.to_string())?;
+            if existing_size == size && existing_modified == modified {
+                continue;
+            }
+        }
+
+        // EXIF parsing and image decoding are blocking filesystem/CPU work;
+        // running them inline would stall the async executor thread for the
+        // whole walk, the same reason `thumbnail.rs` wraps its decode step.
+        let fields = {
+            let absolute = absolute.clone();
+            tauri::async_runtime::spawn_blocking(move || extract_photo_fields(&absolute).unwrap_or_default())
+                .await
+                .map_err(|e| e.to_string())?
+        };
+
+        sqlx::query(
+            "INSERT INTO photos (path, capture_date, camera, gps_latitude, gps_longitude, width, height, size, modified)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)
+             ON CONFLICT(path) DO UPDATE SET
+                capture_date = excluded.capture_date,
+                camera = excluded.camera,
+                gps_latitude = excluded.gps_latitude,
+                gps_longitude = excluded.gps_longitude,
+                width = excluded.width,
+                height = excluded.height,
+                size = excluded.size,
+                modified = excluded.modified",
+        )
+        .bind(&absolute)
+        .bind(&fields.capture_date)
+        .bind(&fields.camera)
+        .bind(fields.gps_latitude)
+        .bind(fields.gps_longitude)
+        .bind(fields.width.map(|w| w as i64))
+        .bind(fields.height.map(|h| h as i64))
+        .bind(size)
+        .bind(modified)
+        .execute(pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        if existing.is_some() {
+            summary.updated += 1;
+        } else {
+            summary.added += 1;
+        }
+    }
+
+    // Scope the removal pass to rows under the scanned root: `path` may be a
+    // subfolder of the library (e.g. "just rescan this album"), and rows for
+    // everything else in the catalog are absent from `seen_paths` simply
+    // because this scan never walked them, not because they were deleted.
+    let mut root_prefix = scan_root.to_string_lossy().into_owned();
+    if !root_prefix.ends_with(std::path::MAIN_SEPARATOR) {
+        root_prefix.push(std::path::MAIN_SEPARATOR);
+    }
+
+    let known_paths: HashSet<String> = sqlx::query_scalar("SELECT path FROM photos")
+        .fetch_all(pool)
+        .await
+        .map_err(|e| e.to_string())?
+        .into_iter()
+        .filter(|known_path| known_path.starts_with(&root_prefix))
+        .collect();
+
+    for known in known_paths.difference(&seen_paths) {
+        sqlx::query("DELETE FROM photos WHERE path = ?")
+            .bind(known)
+            .execute(pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        summary.removed += 1;
+    }
+
+    Ok(summary)
+}
+
+/// Queries the index so the UI can do date-range and camera filtering
+/// without re-reading EXIF from disk on every view.
+#[tauri::command(async)]
+pub async fn query_photos(filter: PhotoFilter, db: State<'_, DbState>) -> Result<Vec<Photo>, String> {
+    let pool = &db.0;
+
+    let mut query = String::from("SELECT * FROM photos WHERE 1 = 1");
+    if filter.camera.is_some() {
+        query.push_str(" AND camera = ?");
+    }
+    if filter.date_from.is_some() {
+        query.push_str(" AND capture_date >= ?");
+    }
+    if filter.date_to.is_some() {
+        query.push_str(" AND capture_date <= ?");
+    }
+
+    let mut bound = sqlx::query_as::<_, Photo>(&query);
+    if let Some(camera) = &filter.camera {
+        bound = bound.bind(camera);
+    }
+    if let Some(date_from) = &filter.date_from {
+        bound = bound.bind(date_from);
+    }
+    if let Some(date_to) = &filter.date_to {
+        bound = bound.bind(date_to);
+    }
+
+    bound.fetch_all(pool).await.map_err(|e| e.to_string())
+}
+
+fn is_supported_image(path: &Path) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .map(|ext| SUPPORTED_EXTENSIONS.contains(&ext.to_lowercase().as_str()))
+        .unwrap_or(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    async fn test_pool() -> SqlitePool {
+        let pool = SqlitePool::connect("sqlite::memory:").await.unwrap();
+        ensure_schema(&pool).await.unwrap();
+        pool
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("onefolder_db_test_{}_{}", std::process::id(), name));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[tokio::test]
+    async fn scan_dir_adds_new_photos_and_skips_unsupported_files() {
+        let root = temp_dir("add");
+        fs::write(root.join("a.jpg"), b"not a real jpeg").unwrap();
+        fs::write(root.join("b.png"), b"not a real png").unwrap();
+        fs::write(root.join("c.txt"), b"ignored, not an image").unwrap();
+
+        let pool = test_pool().await;
+        let summary = scan_dir_impl(root.clone(), &pool).await.unwrap();
+
+        assert_eq!(summary.added, 2);
+        assert_eq!(summary.updated, 0);
+        assert_eq!(summary.removed, 0);
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[tokio::test]
+    async fn scan_dir_updates_changed_files_and_removes_deleted_ones() {
+        let root = temp_dir("update_remove");
+        let photo = root.join("a.jpg");
+        fs::write(&photo, b"version one").unwrap();
+
+        let pool = test_pool().await;
+        scan_dir_impl(root.clone(), &pool).await.unwrap();
+
+        fs::write(&photo, b"version two, a longer body").unwrap();
+        let summary = scan_dir_impl(root.clone(), &pool).await.unwrap();
+        assert_eq!(summary.added, 0);
+        assert_eq!(summary.updated, 1);
+
+        fs::remove_file(&photo).unwrap();
+        let summary = scan_dir_impl(root.clone(), &pool).await.unwrap();
+        assert_eq!(summary.removed, 1);
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[tokio::test]
+    async fn rescanning_a_subfolder_does_not_delete_the_rest_of_the_catalog() {
+        let library = temp_dir("partial_rescan");
+        let album_a = library.join("album_a");
+        let album_b = library.join("album_b");
+        fs::create_dir_all(&album_a).unwrap();
+        fs::create_dir_all(&album_b).unwrap();
+        fs::write(album_a.join("a.jpg"), b"a").unwrap();
+        fs::write(album_b.join("b.jpg"), b"b").unwrap();
+
+        let pool = test_pool().await;
+        scan_dir_impl(library.clone(), &pool).await.unwrap();
+
+        // Rescanning just one album should leave the other album's rows alone.
+        let summary = scan_dir_impl(album_a.clone(), &pool).await.unwrap();
+        assert_eq!(summary.removed, 0);
+
+        let remaining: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM photos")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(remaining, 2);
+
+        let _ = fs::remove_dir_all(&library);
+    }
+}
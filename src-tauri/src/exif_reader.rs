@@ -0,0 +1,171 @@
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::BufReader;
+
+use exif::{In, Tag, Value};
+use serde::Serialize;
+
+/// EXIF fields the UI cares about, plus a catch-all map of everything else
+/// the reader found, keyed by `"<tag> (<ifd_num>)"`.
+#[derive(Debug, Default, Serialize)]
+pub struct ExifMetadata {
+    pub camera_make: Option<String>,
+    pub camera_model: Option<String>,
+    pub lens_model: Option<String>,
+    pub iso: Option<String>,
+    pub exposure_time: Option<String>,
+    pub f_number: Option<String>,
+    pub focal_length: Option<String>,
+    pub date_time_original: Option<String>,
+    pub gps_latitude: Option<f64>,
+    pub gps_longitude: Option<f64>,
+    pub orientation: Option<String>,
+    pub other: HashMap<String, String>,
+}
+
+#[tauri::command]
+pub fn read_exif(path: &str) -> Result<ExifMetadata, String> {
+    let exif = read_container(path)?;
+    let mut metadata = ExifMetadata::default();
+
+    for field in exif.fields() {
+        let value = field.display_value().with_unit(&exif).to_string();
+        match field.tag {
+            Tag::Make => metadata.camera_make = Some(value),
+            Tag::Model => metadata.camera_model = Some(value),
+            Tag::LensModel => metadata.lens_model = Some(value),
+            Tag::PhotographicSensitivity => metadata.iso = Some(value),
+            Tag::ExposureTime => metadata.exposure_time = Some(value),
+            Tag::FNumber => metadata.f_number = Some(value),
+            Tag::FocalLength => metadata.focal_length = Some(value),
+            Tag::DateTimeOriginal => metadata.date_time_original = Some(value),
+            Tag::Orientation => metadata.orientation = Some(value),
+            Tag::GPSLatitude => {
+                let hemisphere = hemisphere_ref(&exif, Tag::GPSLatitudeRef);
+                metadata.gps_latitude = dms_to_decimal(&field.value)
+                    .map(|deg| apply_hemisphere(deg, hemisphere.as_deref(), "S"))
+            }
+            Tag::GPSLongitude => {
+                let hemisphere = hemisphere_ref(&exif, Tag::GPSLongitudeRef);
+                metadata.gps_longitude = dms_to_decimal(&field.value)
+                    .map(|deg| apply_hemisphere(deg, hemisphere.as_deref(), "W"))
+            }
+            _ => {
+                let key = format!("{} ({})", field.tag, field.ifd_num);
+                metadata.other.insert(key, value);
+            }
+        }
+    }
+
+    Ok(metadata)
+}
+
+/// Reads just the numeric orientation tag (1-8), for callers like the
+/// thumbnail generator that need to rotate pixels rather than display text.
+/// Returns `None` when the file has no readable orientation tag.
+pub fn read_orientation(path: &str) -> Option<u32> {
+    let exif = read_container(path).ok()?;
+    exif.get_field(Tag::Orientation, In::PRIMARY)
+        .and_then(|field| field.value.get_uint(0))
+}
+
+/// The subset of [`ExifMetadata`] that the photo index stores a column for.
+#[derive(Debug, Default)]
+pub struct PhotoFields {
+    pub capture_date: Option<String>,
+    pub camera: Option<String>,
+    pub gps_latitude: Option<f64>,
+    pub gps_longitude: Option<f64>,
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+}
+
+pub fn extract_photo_fields(path: &str) -> Option<PhotoFields> {
+    let metadata = read_exif(path).ok()?;
+    let (width, height) = image::image_dimensions(path).map_or((None, None), |(w, h)| (Some(w), Some(h)));
+
+    Some(PhotoFields {
+        capture_date: metadata.date_time_original,
+        camera: match (metadata.camera_make, metadata.camera_model) {
+            (Some(make), Some(model)) => Some(format!("{} {}", make, model)),
+            (Some(make), None) => Some(make),
+            (None, Some(model)) => Some(model),
+            (None, None) => None,
+        },
+        gps_latitude: metadata.gps_latitude,
+        gps_longitude: metadata.gps_longitude,
+        width,
+        height,
+    })
+}
+
+fn read_container(path: &str) -> Result<exif::Exif, String> {
+    let file = File::open(path).map_err(|e| e.to_string())?;
+    let mut bufreader = BufReader::new(&file);
+    exif::Reader::new()
+        .read_from_container(&mut bufreader)
+        .map_err(|e| e.to_string())
+}
+
+fn dms_to_decimal(value: &Value) -> Option<f64> {
+    if let Value::Rational(rationals) = value {
+        if let [degrees, minutes, seconds] = rationals.as_slice() {
+            return Some(degrees.to_f64() + minutes.to_f64() / 60.0 + seconds.to_f64() / 3600.0);
+        }
+    }
+    None
+}
+
+fn hemisphere_ref(exif: &exif::Exif, ref_tag: Tag) -> Option<String> {
+    exif.get_field(ref_tag, In::PRIMARY)
+        .map(|field| field.display_value().to_string())
+}
+
+fn apply_hemisphere(degrees: f64, hemisphere_ref: Option<&str>, negative_ref: &str) -> f64 {
+    if hemisphere_ref == Some(negative_ref) {
+        -degrees
+    } else {
+        degrees
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use exif::Rational;
+
+    #[test]
+    fn dms_to_decimal_converts_degrees_minutes_seconds() {
+        let value = Value::Rational(vec![
+            Rational { num: 40, denom: 1 },
+            Rational { num: 26, denom: 1 },
+            Rational { num: 46, denom: 1 },
+        ]);
+        let decimal = dms_to_decimal(&value).unwrap();
+        assert!((decimal - 40.446_111).abs() < 1e-5);
+    }
+
+    #[test]
+    fn dms_to_decimal_rejects_wrong_arity() {
+        let value = Value::Rational(vec![Rational { num: 1, denom: 1 }]);
+        assert!(dms_to_decimal(&value).is_none());
+    }
+
+    #[test]
+    fn dms_to_decimal_rejects_non_rational_value() {
+        let value = Value::Ascii(vec![b"not rational".to_vec()]);
+        assert!(dms_to_decimal(&value).is_none());
+    }
+
+    #[test]
+    fn apply_hemisphere_negates_for_south_and_west() {
+        assert_eq!(apply_hemisphere(10.0, Some("S"), "S"), -10.0);
+        assert_eq!(apply_hemisphere(10.0, Some("W"), "W"), -10.0);
+    }
+
+    #[test]
+    fn apply_hemisphere_keeps_positive_for_north_and_east() {
+        assert_eq!(apply_hemisphere(10.0, Some("N"), "S"), 10.0);
+        assert_eq!(apply_hemisphere(10.0, None, "S"), 10.0);
+    }
+}
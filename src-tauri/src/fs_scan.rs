@@ -0,0 +1,146 @@
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::Serialize;
+use tauri::State;
+use walkdir::WalkDir;
+
+use crate::settings::SettingsState;
+
+/// Per-entry metadata for a single file or directory, as returned by
+/// [`get_files`]. Timestamps are UNIX epoch millis so the frontend doesn't
+/// have to deal with platform-specific time types.
+#[derive(Debug, Serialize)]
+pub struct EntryMetaData {
+    pub name: String,
+    pub absolute_path: String,
+    pub size: u64,
+    pub is_directory: bool,
+    pub is_file: bool,
+    pub is_symlink: bool,
+    pub child_count: Option<usize>,
+    pub created: Option<u128>,
+    pub modified: Option<u128>,
+    pub accessed: Option<u128>,
+    pub permissions: PermissionInfo,
+}
+
+#[cfg(unix)]
+#[derive(Debug, Serialize)]
+pub struct PermissionInfo {
+    pub mode_octal: String,
+    pub mode_rwx: String,
+}
+
+#[cfg(windows)]
+#[derive(Debug, Serialize)]
+pub struct PermissionInfo {
+    pub read_only: bool,
+    pub hidden: bool,
+}
+
+/// Reads `directory` and returns metadata for every entry in it. Pass
+/// `recursive: true` to walk the whole subtree instead of just the
+/// immediate children, so the frontend can build the folder tree the app
+/// is named for. `directory` defaults to the configured library root.
+///
+/// Entries WalkDir can't stat (permission-denied subdirectories, a file
+/// deleted mid-walk) are skipped rather than aborting the whole scan —
+/// a real photo library will commonly contain at least one of these.
+#[tauri::command]
+pub fn get_files(
+    directory: Option<String>,
+    recursive: bool,
+    settings: State<SettingsState>,
+) -> Result<Vec<EntryMetaData>, String> {
+    let directory = settings.resolve(directory);
+    // Canonicalize the root once so walked entries are already absolute;
+    // canonicalizing each entry individually would resolve symlinks to
+    // their targets, which disagrees with an `is_symlink: true` flag that
+    // describes the link itself.
+    let root = directory.canonicalize().unwrap_or(directory);
+    let mut walker = WalkDir::new(&root).min_depth(1);
+    if !recursive {
+        walker = walker.max_depth(1);
+    }
+
+    Ok(walker
+        .into_iter()
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| entry_to_metadata(entry.path()).ok())
+        .collect())
+}
+
+fn entry_to_metadata(path: &Path) -> Result<EntryMetaData, String> {
+    let metadata = std::fs::symlink_metadata(path).map_err(|e| e.to_string())?;
+    let file_type = metadata.file_type();
+
+    let child_count = if file_type.is_dir() {
+        std::fs::read_dir(path).ok().map(|entries| entries.count())
+    } else {
+        None
+    };
+
+    Ok(EntryMetaData {
+        name: path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default(),
+        absolute_path: path.to_string_lossy().into_owned(),
+        size: metadata.len(),
+        is_directory: file_type.is_dir(),
+        is_file: file_type.is_file(),
+        is_symlink: file_type.is_symlink(),
+        child_count,
+        created: to_millis(metadata.created().ok()),
+        modified: to_millis(metadata.modified().ok()),
+        accessed: to_millis(metadata.accessed().ok()),
+        permissions: read_permissions(&metadata),
+    })
+}
+
+fn to_millis(time: Option<SystemTime>) -> Option<u128> {
+    time.and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+        .map(|d| d.as_millis())
+}
+
+#[cfg(unix)]
+fn read_permissions(metadata: &std::fs::Metadata) -> PermissionInfo {
+    use std::os::unix::fs::PermissionsExt;
+
+    let mode = metadata.permissions().mode();
+    PermissionInfo {
+        mode_octal: format!("{:o}", mode & 0o777),
+        mode_rwx: mode_to_rwx(mode),
+    }
+}
+
+#[cfg(unix)]
+fn mode_to_rwx(mode: u32) -> String {
+    const BITS: [(u32, char); 9] = [
+        (0o400, 'r'),
+        (0o200, 'w'),
+        (0o100, 'x'),
+        (0o040, 'r'),
+        (0o020, 'w'),
+        (0o010, 'x'),
+        (0o004, 'r'),
+        (0o002, 'w'),
+        (0o001, 'x'),
+    ];
+
+    BITS.iter()
+        .map(|(mask, ch)| if mode & mask != 0 { *ch } else { '-' })
+        .collect()
+}
+
+#[cfg(windows)]
+fn read_permissions(metadata: &std::fs::Metadata) -> PermissionInfo {
+    use std::os::windows::fs::MetadataExt;
+
+    const FILE_ATTRIBUTE_HIDDEN: u32 = 0x2;
+    PermissionInfo {
+        read_only: metadata.permissions().readonly(),
+        hidden: metadata.file_attributes() & FILE_ATTRIBUTE_HIDDEN != 0,
+    }
+}
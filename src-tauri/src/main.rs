@@ -1,25 +1,51 @@
 // Prevents additional console window on Windows in release, DO NOT REMOVE!!
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
+mod db;
+mod exif_reader;
+mod fs_scan;
+mod protocol;
+mod settings;
+mod thumbnail;
+
+use std::sync::Mutex;
+
+use tauri::Manager;
+
+use db::{query_photos, scan_dir, DbState};
+use exif_reader::read_exif;
+use fs_scan::get_files;
+use settings::{get_settings, set_library_root, SettingsState};
+use thumbnail::get_thumbnail;
+
 // Learn more about Tauri commands at https://tauri.app/v1/guides/features/command
 #[tauri::command]
 fn greet(name: &str) -> String {
     format!("Hello, {}! You've been greeted from Rust!wsdasd", name)
 }
 
-// #[tauri::command]
-// fn get_files(name: &str) -> String {
-//     format!("Hello, {}! You've been greeted from Rust!wsdasd", name)
-// }
-
-// fn read_file_string(filepath: &str) -> Result<String, Box<dyn std::error::Error>> {
-//     let data = fs::read_to_string(filepath)?;
-//     Ok(data)
-// }
-
 fn main() {
     tauri::Builder::default()
-        .invoke_handler(tauri::generate_handler![greet])
+        .setup(|app| {
+            let settings = settings::load_or_init(&app.handle())?;
+            app.manage(SettingsState(Mutex::new(settings)));
+
+            let pool = tauri::async_runtime::block_on(db::init_pool(&app.handle()))?;
+            app.manage(DbState(pool));
+
+            Ok(())
+        })
+        .register_uri_scheme_protocol(protocol::SCHEME, |_app, request| protocol::handle(request))
+        .invoke_handler(tauri::generate_handler![
+            greet,
+            read_exif,
+            get_files,
+            get_thumbnail,
+            scan_dir,
+            query_photos,
+            get_settings,
+            set_library_root
+        ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
 }
@@ -0,0 +1,173 @@
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::PathBuf;
+
+use tauri::http::{Request, Response, ResponseBuilder};
+
+/// Scheme the frontend can reference directly in `<img>`/`<video>` tags,
+/// e.g. `onefolder://path/to/photo.jpg`, instead of shipping bytes through
+/// base64-encoded IPC.
+pub const SCHEME: &str = "onefolder";
+
+pub fn handle(request: &Request) -> Result<Response, Box<dyn std::error::Error>> {
+    let path = resolve_path(request.uri())?;
+
+    let mut file = match File::open(&path) {
+        Ok(file) => file,
+        Err(_) => return ResponseBuilder::new().status(404).body(Vec::new()),
+    };
+
+    let file_len = file.metadata()?.len();
+    let mime = mime_type_for(&path);
+
+    if let Some(range) = request.headers().get("range").and_then(|v| v.to_str().ok()) {
+        return match parse_range(range, file_len) {
+            Ok((start, end)) => {
+                let chunk_len = (end - start + 1) as usize;
+
+                file.seek(SeekFrom::Start(start))?;
+                let mut buffer = vec![0u8; chunk_len];
+                file.read_exact(&mut buffer)?;
+
+                ResponseBuilder::new()
+                    .status(206)
+                    .header("Content-Type", mime)
+                    .header("Content-Length", chunk_len.to_string())
+                    .header("Content-Range", format!("bytes {}-{}/{}", start, end, file_len))
+                    .header("Accept-Ranges", "bytes")
+                    .body(buffer)
+            }
+            Err(RangeParseError::NotSatisfiable) => ResponseBuilder::new()
+                .status(416)
+                .header("Content-Range", format!("bytes */{}", file_len))
+                .body(Vec::new()),
+            Err(RangeParseError::Malformed) => ResponseBuilder::new().status(400).body(Vec::new()),
+        };
+    }
+
+    let mut buffer = Vec::with_capacity(file_len as usize);
+    file.read_to_end(&mut buffer)?;
+
+    ResponseBuilder::new()
+        .status(200)
+        .header("Content-Type", mime)
+        .header("Content-Length", file_len.to_string())
+        .header("Accept-Ranges", "bytes")
+        .body(buffer)
+}
+
+fn resolve_path(uri: &str) -> Result<PathBuf, Box<dyn std::error::Error>> {
+    let stripped = uri
+        .strip_prefix(&format!("{}://localhost/", SCHEME))
+        .or_else(|| uri.strip_prefix(&format!("{}://", SCHEME)))
+        .unwrap_or(uri);
+    let decoded = percent_encoding::percent_decode_str(stripped).decode_utf8_lossy();
+    Ok(PathBuf::from(decoded.into_owned()))
+}
+
+fn mime_type_for(path: &PathBuf) -> &'static str {
+    match path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .unwrap_or("")
+        .to_lowercase()
+        .as_str()
+    {
+        "jpg" | "jpeg" => "image/jpeg",
+        "png" => "image/png",
+        "webp" => "image/webp",
+        "gif" => "image/gif",
+        "tif" | "tiff" => "image/tiff",
+        "mp4" => "video/mp4",
+        _ => "application/octet-stream",
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum RangeParseError {
+    /// The header wasn't a well-formed `bytes=start-end` spec.
+    Malformed,
+    /// The header parsed fine but describes a range the file can't satisfy
+    /// (start past EOF, or start after end) — callers should answer 416.
+    NotSatisfiable,
+}
+
+/// Parses a single `bytes=start-end` range header, clamping `end` to the
+/// end of the file when omitted (the common "give me everything from
+/// `start`" form used by seek-ahead video players).
+fn parse_range(range: &str, file_len: u64) -> Result<(u64, u64), RangeParseError> {
+    let spec = range.strip_prefix("bytes=").ok_or(RangeParseError::Malformed)?;
+    let mut parts = spec.splitn(2, '-');
+    let start: u64 = parts
+        .next()
+        .unwrap_or("")
+        .parse()
+        .map_err(|_| RangeParseError::Malformed)?;
+    let end: u64 = match parts.next().filter(|s| !s.is_empty()) {
+        Some(s) => s.parse().map_err(|_| RangeParseError::Malformed)?,
+        None => file_len.saturating_sub(1),
+    };
+
+    if start >= file_len || start > end {
+        return Err(RangeParseError::NotSatisfiable);
+    }
+
+    Ok((start, end.min(file_len - 1)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_explicit_start_and_end() {
+        assert_eq!(parse_range("bytes=10-20", 100), Ok((10, 20)));
+    }
+
+    #[test]
+    fn defaults_end_to_last_byte_when_omitted() {
+        assert_eq!(parse_range("bytes=10-", 100), Ok((10, 99)));
+    }
+
+    #[test]
+    fn clamps_end_past_eof_down_to_last_byte() {
+        assert_eq!(parse_range("bytes=10-99999", 100), Ok((10, 99)));
+    }
+
+    #[test]
+    fn rejects_start_past_eof() {
+        assert_eq!(
+            parse_range("bytes=99999-", 100),
+            Err(RangeParseError::NotSatisfiable)
+        );
+    }
+
+    #[test]
+    fn rejects_start_past_eof_on_empty_file() {
+        assert_eq!(
+            parse_range("bytes=0-", 0),
+            Err(RangeParseError::NotSatisfiable)
+        );
+    }
+
+    #[test]
+    fn rejects_start_after_end() {
+        assert_eq!(
+            parse_range("bytes=50-10", 100),
+            Err(RangeParseError::NotSatisfiable)
+        );
+    }
+
+    #[test]
+    fn rejects_missing_bytes_prefix() {
+        assert_eq!(parse_range("10-20", 100), Err(RangeParseError::Malformed));
+    }
+
+    #[test]
+    fn rejects_non_numeric_start() {
+        assert_eq!(
+            parse_range("bytes=abc-20", 100),
+            Err(RangeParseError::Malformed)
+        );
+    }
+}
@@ -0,0 +1,91 @@
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+use tauri::AppHandle;
+
+const SETTINGS_FILE: &str = "settings.json";
+
+/// User-configurable settings, persisted as `settings.json` under the
+/// resolved app config directory.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AppSettings {
+    pub library_root: PathBuf,
+}
+
+/// Managed state wrapping the current settings so commands can read and
+/// update them without re-reading `settings.json` on every call.
+pub struct SettingsState(pub Mutex<AppSettings>);
+
+impl SettingsState {
+    /// Returns `path` if given, otherwise the configured library root, so
+    /// scanning/thumbnail/index commands work without the caller having to
+    /// know where the library lives.
+    pub fn resolve(&self, path: Option<String>) -> PathBuf {
+        match path {
+            Some(path) => PathBuf::from(path),
+            None => self.0.lock().unwrap().library_root.clone(),
+        }
+    }
+}
+
+/// Loads `settings.json` from the app config dir, creating it (with a
+/// `library` folder under the app data dir as the default library root)
+/// if it doesn't exist yet.
+/// Called from the `.setup()` hook so every subsystem can derive its paths
+/// from the configured root instead of the process's current directory.
+pub fn load_or_init(app: &AppHandle) -> Result<AppSettings, String> {
+    let settings_path = settings_path(app)?;
+
+    if let Ok(contents) = fs::read_to_string(&settings_path) {
+        if let Ok(settings) = serde_json::from_str::<AppSettings>(&contents) {
+            return Ok(settings);
+        }
+    }
+
+    let default_root = app
+        .path_resolver()
+        .app_data_dir()
+        .ok_or_else(|| "could not resolve app data directory".to_string())?
+        .join("library");
+    fs::create_dir_all(&default_root).map_err(|e| e.to_string())?;
+
+    let settings = AppSettings {
+        library_root: default_root,
+    };
+    write_settings(&settings_path, &settings)?;
+    Ok(settings)
+}
+
+fn settings_path(app: &AppHandle) -> Result<PathBuf, String> {
+    let config_dir = app
+        .path_resolver()
+        .app_config_dir()
+        .ok_or_else(|| "could not resolve app config directory".to_string())?;
+    fs::create_dir_all(&config_dir).map_err(|e| e.to_string())?;
+    Ok(config_dir.join(SETTINGS_FILE))
+}
+
+fn write_settings(path: &PathBuf, settings: &AppSettings) -> Result<(), String> {
+    let contents = serde_json::to_string_pretty(settings).map_err(|e| e.to_string())?;
+    fs::write(path, contents).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn get_settings(state: tauri::State<SettingsState>) -> AppSettings {
+    state.0.lock().unwrap().clone()
+}
+
+#[tauri::command]
+pub fn set_library_root(
+    app: AppHandle,
+    state: tauri::State<SettingsState>,
+    path: String,
+) -> Result<(), String> {
+    let settings_path = settings_path(&app)?;
+
+    let mut settings = state.0.lock().unwrap();
+    settings.library_root = PathBuf::from(path);
+    write_settings(&settings_path, &settings)
+}
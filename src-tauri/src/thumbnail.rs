@@ -0,0 +1,109 @@
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+use std::time::UNIX_EPOCH;
+
+use image::imageops::FilterType;
+use image::{DynamicImage, GenericImageView};
+use tauri::AppHandle;
+use tokio::sync::Semaphore;
+
+use crate::exif_reader::read_orientation;
+
+/// Caps how many decode/resize/encode jobs run at once, so scanning a
+/// folder of thousands of photos doesn't spawn an unbounded number of
+/// worker threads.
+const MAX_CONCURRENT_THUMBNAILS: usize = 4;
+
+fn thumbnail_semaphore() -> &'static Semaphore {
+    static SEMAPHORE: OnceLock<Semaphore> = OnceLock::new();
+    SEMAPHORE.get_or_init(|| Semaphore::new(MAX_CONCURRENT_THUMBNAILS))
+}
+
+/// Returns the path to a cached WebP thumbnail for `path`, generating it
+/// first if needed. The cache key folds in the source file's mtime, so
+/// edits to the original regenerate the thumbnail instead of serving a
+/// stale one.
+#[tauri::command(async)]
+pub async fn get_thumbnail(app: AppHandle, path: String, max_edge: u32) -> Result<PathBuf, String> {
+    let cache_path = cache_path_for(&app, &path, max_edge)?;
+    if cache_path.exists() {
+        return Ok(cache_path);
+    }
+
+    let _permit = thumbnail_semaphore()
+        .acquire()
+        .await
+        .map_err(|e| e.to_string())?;
+
+    // Another request may have generated it while we were waiting on the semaphore.
+    if cache_path.exists() {
+        return Ok(cache_path);
+    }
+
+    tauri::async_runtime::spawn_blocking({
+        let path = path.clone();
+        let cache_path = cache_path.clone();
+        move || generate_thumbnail(&path, &cache_path, max_edge)
+    })
+    .await
+    .map_err(|e| e.to_string())??;
+
+    Ok(cache_path)
+}
+
+fn cache_path_for(app: &AppHandle, path: &str, max_edge: u32) -> Result<PathBuf, String> {
+    let metadata = std::fs::metadata(path).map_err(|e| e.to_string())?;
+    let modified_secs = metadata
+        .modified()
+        .ok()
+        .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+        .map(|duration| duration.as_secs())
+        .unwrap_or(0);
+
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    (path, modified_secs, max_edge).hash(&mut hasher);
+    let cache_key = hasher.finish();
+
+    // Derived from the app data dir (the same root `db.rs`'s library index
+    // and `settings.rs`'s default library root use) rather than the OS
+    // cache dir. The cache location does not follow `set_library_root`:
+    // changing the library root leaves the thumbnails where they are.
+    let cache_dir = app
+        .path_resolver()
+        .app_data_dir()
+        .ok_or_else(|| "could not resolve app data directory".to_string())?
+        .join("thumbnails");
+    std::fs::create_dir_all(&cache_dir).map_err(|e| e.to_string())?;
+
+    Ok(cache_dir.join(format!("{:x}.webp", cache_key)))
+}
+
+fn generate_thumbnail(source: &str, dest: &Path, max_edge: u32) -> Result<(), String> {
+    let orientation = read_orientation(source).unwrap_or(1);
+    let image = apply_orientation(image::open(source).map_err(|e| e.to_string())?, orientation);
+
+    let (width, height) = image.dimensions();
+    let scale = max_edge as f32 / width.max(height) as f32;
+
+    let resized = if scale < 1.0 {
+        let new_width = (width as f32 * scale).round().max(1.0) as u32;
+        let new_height = (height as f32 * scale).round().max(1.0) as u32;
+        image.resize(new_width, new_height, FilterType::Lanczos3)
+    } else {
+        image
+    };
+
+    resized
+        .save_with_format(dest, image::ImageFormat::WebP)
+        .map_err(|e| e.to_string())
+}
+
+fn apply_orientation(image: DynamicImage, orientation: u32) -> DynamicImage {
+    match orientation {
+        3 => image.rotate180(),
+        6 => image.rotate90(),
+        8 => image.rotate270(),
+        _ => image,
+    }
+}